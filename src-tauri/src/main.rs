@@ -2,94 +2,319 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 use std::collections::HashMap;
-use std::process::{Child, Command};
 use std::sync::{Arc, Mutex};
-use tauri::{AppHandle, Manager, State};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Manager, State};
+use tauri::path::BaseDirectory;
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+use tauri_plugin_shell::process::{CommandChild, CommandEvent};
+use tauri_plugin_shell::ShellExt;
 use tokio::time::{sleep, Duration};
 use log::{info, error, warn};
 
+// Emitted on the "backend-log" event so the frontend can render a live log panel.
+#[derive(Clone, Serialize)]
+struct LogLine {
+    level: String,
+    line: String,
+}
+
+// How long to wait for the backend to answer its health check before giving up.
+const READINESS_TIMEOUT: Duration = Duration::from_secs(15);
+const READINESS_POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+// Backoff schedule for the crash supervisor below.
+const RESTART_INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+const RESTART_MAX_BACKOFF: Duration = Duration::from_secs(16);
+const RESTART_MAX_ATTEMPTS: u32 = 5;
+
+#[derive(Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BackendState {
+    Stopped,
+    Starting,
+    Running,
+    Restarting,
+    Failed,
+}
+
+// Returned by `get_backend_status` so the frontend can distinguish a clean
+// stop from a backend that's mid-restart or has given up entirely.
+#[derive(Clone, Serialize)]
+pub struct BackendStatus {
+    state: BackendState,
+    restart_attempts: u32,
+    // Milliseconds since the Unix epoch; `None` if the backend has never crashed.
+    last_crash_unix_ms: Option<u64>,
+}
+
 // Python backend manager
 pub struct PythonBackend {
-    process: Arc<Mutex<Option<Child>>>,
-    is_running: Arc<Mutex<bool>>,
+    process: Arc<Mutex<Option<CommandChild>>>,
+    // Bumped every time start_sidecar spawns a new child, and compared
+    // against the generation each reader task was spawned with. This is what
+    // lets the `Terminated` handler tell "my child died" apart from "a stale
+    // event for a previous child arrived after a newer one took its slot" —
+    // presence-in-`process` alone can't distinguish the two once a restart
+    // has already stored a fresh child there.
+    process_generation: Arc<Mutex<u64>>,
+    state: Arc<Mutex<BackendState>>,
+    restart_attempts: Arc<Mutex<u32>>,
+    last_crash: Arc<Mutex<Option<std::time::SystemTime>>>,
+    port: Arc<Mutex<Option<u16>>>,
+    // Serializes the whole start() sequence so `restart_backend`, the crash
+    // supervisor, and the toggle_backend shortcut can't race each other into
+    // spawning two sidecars and orphaning one.
+    start_lock: Arc<tokio::sync::Mutex<()>>,
+}
+
+// Poll the backend's health endpoint until it responds successfully, `timeout`
+// elapses, or `crashed` fires because the process exited first — whichever
+// happens first wins, so a backend that dies on launch fails fast instead of
+// burning the full timeout retrying a refused connection.
+async fn wait_until_ready(
+    port: u16,
+    timeout: Duration,
+    crashed: tokio::sync::oneshot::Receiver<String>,
+) -> Result<(), String> {
+    tokio::select! {
+        result = poll_health(port, timeout) => result,
+        crash = crashed => Err(crash.unwrap_or_else(|_| "backend process exited unexpectedly".to_string())),
+    }
+}
+
+async fn poll_health(port: u16, timeout: Duration) -> Result<(), String> {
+    let client = reqwest::Client::new();
+    let url = format!("http://127.0.0.1:{}/health", port);
+    let deadline = tokio::time::Instant::now() + timeout;
+
+    loop {
+        if let Ok(response) = client.get(&url).send().await {
+            if response.status().is_success() {
+                return Ok(());
+            }
+        }
+
+        if tokio::time::Instant::now() >= deadline {
+            return Err(format!("backend did not become ready within {:?}", timeout));
+        }
+
+        sleep(READINESS_POLL_INTERVAL).await;
+    }
 }
 
 impl PythonBackend {
     pub fn new() -> Self {
         Self {
             process: Arc::new(Mutex::new(None)),
-            is_running: Arc::new(Mutex::new(false)),
+            process_generation: Arc::new(Mutex::new(0)),
+            state: Arc::new(Mutex::new(BackendState::Stopped)),
+            restart_attempts: Arc::new(Mutex::new(0)),
+            last_crash: Arc::new(Mutex::new(None)),
+            port: Arc::new(Mutex::new(None)),
+            start_lock: Arc::new(tokio::sync::Mutex::new(())),
         }
     }
 
+    pub fn port(&self) -> Option<u16> {
+        *self.port.lock().unwrap()
+    }
+
     pub async fn start(&self, app_handle: &AppHandle) -> Result<(), Box<dyn std::error::Error>> {
+        // Only one in-flight start() at a time: restart_backend, the crash
+        // supervisor, and the toggle_backend shortcut can all call this
+        // independently, and without this lock two callers could both pass
+        // the "not already running" check below and each spawn their own
+        // sidecar, orphaning whichever one loses the race to store its
+        // process handle and port.
+        let _start_guard = self.start_lock.lock().await;
+
         // Check if already running
         {
-            let is_running = self.is_running.lock().unwrap();
-            if *is_running {
+            if *self.state.lock().unwrap() == BackendState::Running {
                 info!("Python backend already running");
                 return Ok(());
             }
         }
 
-        // Get app data directory
+        *self.state.lock().unwrap() = BackendState::Starting;
+
+        let outcome = self.start_sidecar(app_handle).await;
+
+        // If we bailed out before a sidecar was even spawned (e.g. the
+        // filesystem setup below failed), nothing else will ever move us out
+        // of `Starting`, so reset it here. Failures after that point already
+        // route through `stop()`, which sets `Stopped` itself.
+        if outcome.is_err() && *self.state.lock().unwrap() == BackendState::Starting {
+            *self.state.lock().unwrap() = BackendState::Stopped;
+        }
+
+        outcome
+    }
+
+    async fn start_sidecar(&self, app_handle: &AppHandle) -> Result<(), Box<dyn std::error::Error>> {
+        // Get app data directory; this is where per-user config/data live,
+        // as opposed to the read-only bundled resources shipped with the app.
         let app_data_dir = app_handle.path().app_data_dir()
             .map_err(|e| format!("Failed to get app data directory: {}", e))?;
-        
-        // For development/testing, use a hardcoded path to the project directory
-        // In production, you might want to bundle the backend or use a different approach
-        let project_root = std::path::PathBuf::from("/Users/alexpelletier/Documents/gmapsscraper");
-        
-        let backend_dir = project_root.join("backend");
-        let config_path = project_root.join("config").join("config.yaml");
-        let data_path = project_root.join("data");
+
+        let config_path = app_data_dir.join("config").join("config.yaml");
+        let data_path = app_data_dir.join("data");
 
         info!("Starting Python backend");
-        info!("Project root: {:?}", project_root);
-        info!("Backend directory: {:?}", backend_dir);
+        info!("App data directory: {:?}", app_data_dir);
         info!("Config path: {:?}", config_path);
         info!("Data path: {:?}", data_path);
 
-        // Verify paths exist
-        if !backend_dir.exists() {
-            return Err(format!("Backend directory not found: {:?}", backend_dir).into());
+        // First run: materialize a default config and data directory under
+        // the app data dir so the bundled backend has somewhere to read/write.
+        if let Some(config_dir) = config_path.parent() {
+            std::fs::create_dir_all(config_dir)
+                .map_err(|e| format!("Failed to create config directory: {}", e))?;
         }
         if !config_path.exists() {
-            return Err(format!("Config file not found: {:?}", config_path).into());
-        }
-        if !data_path.exists() {
-            return Err(format!("Data directory not found: {:?}", data_path).into());
+            let default_config = app_handle.path()
+                .resolve("resources/config.default.yaml", BaseDirectory::Resource)
+                .map_err(|e| format!("Failed to resolve default config resource: {}", e))?;
+            std::fs::copy(&default_config, &config_path)
+                .map_err(|e| format!("Failed to write default config: {}", e))?;
         }
+        std::fs::create_dir_all(&data_path)
+            .map_err(|e| format!("Failed to create data directory: {}", e))?;
 
         // Set up environment variables
         let mut env_vars = HashMap::new();
         env_vars.insert("GMAPS_CONFIG_PATH".to_string(), config_path.to_string_lossy().to_string());
         env_vars.insert("GMAPS_DATA_PATH".to_string(), data_path.to_string_lossy().to_string());
 
-        // Start Python backend process
-        let mut cmd = Command::new("python3");
-        cmd.current_dir(&backend_dir)
-            .args(&["-m", "uvicorn", "api.server:app", "--host", "127.0.0.1", "--port", "8000"])
-            .envs(&env_vars);
+        // Bind an ephemeral port ourselves rather than hardcoding one, so we
+        // don't collide with another process (or another instance of this
+        // app) already sitting on a fixed port.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")
+            .map_err(|e| format!("Failed to reserve a backend port: {}", e))?;
+        let port = listener.local_addr()
+            .map_err(|e| format!("Failed to read reserved backend port: {}", e))?
+            .port();
+        drop(listener);
+        *self.port.lock().unwrap() = Some(port);
 
-        match cmd.spawn() {
-            Ok(child) => {
-                info!("Python backend started successfully with PID: {}", child.id());
-                
-                // Store the process and update status
-                {
+        // Launch the bundled backend as a Tauri sidecar rather than shelling
+        // out to a system python3, so packaged releases don't depend on the
+        // host having a matching Python environment.
+        let sidecar_command = app_handle.shell().sidecar("gmaps-backend")
+            .map_err(|e| format!("Failed to resolve backend sidecar: {}", e))?
+            .envs(env_vars)
+            .args(["--host", "127.0.0.1", "--port", &port.to_string()]);
+
+        match sidecar_command.spawn() {
+            Ok((mut rx, child)) => {
+                info!("Python backend started successfully with PID: {}", child.pid());
+
+                // Store the process handle. `state` stays out of `Running` until the
+                // readiness probe below confirms the backend is actually serving.
+                // Bump the generation counter too: a `Terminated` event for a
+                // previous child can still arrive after this one is stored
+                // (the shell plugin gives no ordering guarantee), and presence
+                // in `process` alone can't tell the two apart once a restart
+                // has already overwritten the slot. The reader task below
+                // captures this generation and compares it before treating
+                // its `Terminated` event as belonging to this child.
+                let my_generation = {
                     let mut process = self.process.lock().unwrap();
                     *process = Some(child);
+                    let mut generation = self.process_generation.lock().unwrap();
+                    *generation += 1;
+                    *generation
+                };
+
+                // Lets the readiness probe below fail fast if the process exits
+                // before ever answering a health check, instead of polling a
+                // refused connection for the whole READINESS_TIMEOUT.
+                let (ready_crash_tx, ready_crash_rx) = tokio::sync::oneshot::channel::<String>();
+                let mut ready_crash_tx = Some(ready_crash_tx);
+
+                // Stream stdout/stderr lines to the frontend as they arrive,
+                // and react to the process terminating on its own.
+                let events_handle = app_handle.clone();
+                tauri::async_runtime::spawn(async move {
+                    while let Some(event) = rx.recv().await {
+                        match event {
+                            CommandEvent::Stdout(bytes) => {
+                                let line = String::from_utf8_lossy(&bytes).trim_end().to_string();
+                                let _ = events_handle.emit("backend-log", LogLine { level: "info".to_string(), line });
+                            }
+                            CommandEvent::Stderr(bytes) => {
+                                let line = String::from_utf8_lossy(&bytes).trim_end().to_string();
+                                let _ = events_handle.emit("backend-log", LogLine { level: "error".to_string(), line });
+                            }
+                            CommandEvent::Terminated(payload) => {
+                                let backend: State<PythonBackend> = events_handle.state();
+                                // Only treat this as a crash if this event belongs to the
+                                // generation we were spawned for. A stale event for a child
+                                // that was already replaced by a newer one (e.g. stop()
+                                // followed quickly by start()) would otherwise be
+                                // misattributed to the current process, since the slot it
+                                // left behind has since been refilled.
+                                let is_current_generation =
+                                    *backend.process_generation.lock().unwrap() == my_generation;
+                                if !is_current_generation {
+                                    continue;
+                                }
+                                // stop() takes the child out of `process` before killing
+                                // it, so an empty slot here means the exit was requested.
+                                let was_stopped = backend.process.lock().unwrap().take().is_none();
+                                if was_stopped {
+                                    continue;
+                                }
+                                warn!("Python backend process terminated unexpectedly: {:?}", payload);
+                                *backend.last_crash.lock().unwrap() = Some(std::time::SystemTime::now());
+                                let _ = events_handle.emit("backend-crashed", ());
+
+                                if let Some(tx) = ready_crash_tx.take() {
+                                    let _ = tx.send(format!("backend exited before becoming ready: {:?}", payload));
+                                }
+
+                                let was_running = {
+                                    let mut state = backend.state.lock().unwrap();
+                                    let running = *state == BackendState::Running;
+                                    if running {
+                                        *state = BackendState::Restarting;
+                                    }
+                                    running
+                                };
+                                if was_running {
+                                    let supervisor_handle = events_handle.clone();
+                                    tauri::async_runtime::spawn(async move {
+                                        supervise_restart(supervisor_handle).await;
+                                    });
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+                });
+
+                // Don't declare success until the backend actually answers its
+                // health check; a 2-second sleep was racy and papered over
+                // failures to bind the port. Only emit "backend-started" once
+                // that's confirmed, so the frontend never sees a "started"
+                // event immediately followed by "backend-crashed" for a
+                // backend that never actually came up.
+                match wait_until_ready(port, READINESS_TIMEOUT, ready_crash_rx).await {
+                    Ok(()) => {
+                        *self.state.lock().unwrap() = BackendState::Running;
+                        *self.restart_attempts.lock().unwrap() = 0;
+                        let _ = app_handle.emit("backend-started", ());
+                        Ok(())
+                    }
+                    Err(e) => {
+                        error!("Python backend failed readiness check: {}", e);
+                        self.stop(app_handle);
+                        *self.last_crash.lock().unwrap() = Some(std::time::SystemTime::now());
+                        let _ = app_handle.emit("backend-crashed", ());
+                        Err(e.into())
+                    }
                 }
-                {
-                    let mut is_running = self.is_running.lock().unwrap();
-                    *is_running = true;
-                }
-                
-                // Wait a moment for backend to initialize
-                sleep(Duration::from_secs(2)).await;
-                
-                Ok(())
             }
             Err(e) => {
                 error!("Failed to start Python backend: {}", e);
@@ -98,32 +323,226 @@ impl PythonBackend {
         }
     }
 
-    pub fn stop(&self) {
+    pub fn stop(&self, app_handle: &AppHandle) {
         let mut process = self.process.lock().unwrap();
-        let mut is_running = self.is_running.lock().unwrap();
-        
-        if let Some(mut child) = process.take() {
+
+        if let Some(child) = process.take() {
             info!("Stopping Python backend");
             if let Err(e) = child.kill() {
                 warn!("Failed to kill Python backend process: {}", e);
             }
-            if let Err(e) = child.wait() {
-                warn!("Failed to wait for Python backend process: {}", e);
-            }
+            let _ = app_handle.emit("backend-stopped", ());
         }
-        
-        *is_running = false;
+
+        *self.state.lock().unwrap() = BackendState::Stopped;
+        *self.port.lock().unwrap() = None;
     }
 
     pub fn is_running(&self) -> bool {
-        *self.is_running.lock().unwrap()
+        *self.state.lock().unwrap() == BackendState::Running
+    }
+
+    pub fn status(&self) -> BackendStatus {
+        let last_crash_unix_ms = self.last_crash.lock().unwrap()
+            .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+            .map(|d| d.as_millis() as u64);
+        BackendStatus {
+            state: *self.state.lock().unwrap(),
+            restart_attempts: *self.restart_attempts.lock().unwrap(),
+            last_crash_unix_ms,
+        }
     }
 }
 
+// Attempts to restart a backend that crashed unexpectedly, backing off
+// exponentially between tries and giving up after RESTART_MAX_ATTEMPTS.
+async fn supervise_restart(app_handle: AppHandle) {
+    let mut backoff = RESTART_INITIAL_BACKOFF;
+
+    loop {
+        let backend: State<PythonBackend> = app_handle.state();
+
+        let attempt = {
+            let mut attempts = backend.restart_attempts.lock().unwrap();
+            *attempts += 1;
+            *attempts
+        };
+
+        if attempt > RESTART_MAX_ATTEMPTS {
+            error!("Python backend crashed {} times in a row; giving up", attempt - 1);
+            *backend.state.lock().unwrap() = BackendState::Failed;
+            let _ = app_handle.emit("backend-failed", ());
+            return;
+        }
+
+        warn!("Restarting Python backend in {:?} (attempt {}/{})", backoff, attempt, RESTART_MAX_ATTEMPTS);
+        sleep(backoff).await;
+
+        match backend.start(&app_handle).await {
+            Ok(()) => {
+                info!("Python backend recovered after {} attempt(s)", attempt);
+                return;
+            }
+            Err(e) => {
+                error!("Restart attempt {} failed: {}", attempt, e);
+                // The failed start() left `Stopped`; keep reporting a restart in progress.
+                *backend.state.lock().unwrap() = BackendState::Restarting;
+                backoff = (backoff * 2).min(RESTART_MAX_BACKOFF);
+            }
+        }
+    }
+}
+
+// Forwards a `gmaps://` request to the backend's current ephemeral port.
+// Keeping the frontend on a stable custom-protocol origin means it never
+// needs to know (or hardcode) which TCP port the backend ended up on.
+async fn proxy_to_backend(
+    app_handle: &AppHandle,
+    request: tauri::http::Request<Vec<u8>>,
+) -> tauri::http::Response<Vec<u8>> {
+    let port = {
+        let backend: State<PythonBackend> = app_handle.state();
+        backend.port()
+    };
+
+    let Some(port) = port else {
+        return tauri::http::Response::builder()
+            .status(503)
+            .body(b"backend is not running".to_vec())
+            .unwrap();
+    };
+
+    let path_and_query = request.uri().path_and_query().map(|p| p.as_str()).unwrap_or("/");
+    let target = format!("http://127.0.0.1:{}{}", port, path_and_query);
+
+    let client = reqwest::Client::new();
+    let mut proxied = client.request(request.method().clone(), &target);
+    for (name, value) in request.headers() {
+        proxied = proxied.header(name, value);
+    }
+    if !request.body().is_empty() {
+        proxied = proxied.body(request.body().clone());
+    }
+
+    match proxied.send().await {
+        Ok(backend_response) => {
+            let status = backend_response.status();
+            let headers = backend_response.headers().clone();
+            let body = backend_response.bytes().await.unwrap_or_default().to_vec();
+
+            let mut builder = tauri::http::Response::builder().status(status.as_u16());
+            for (name, value) in headers.iter() {
+                builder = builder.header(name, value);
+            }
+            builder.body(body).unwrap()
+        }
+        Err(e) => {
+            error!("Failed to proxy gmaps:// request to backend: {}", e);
+            tauri::http::Response::builder()
+                .status(502)
+                .body(b"failed to reach backend".to_vec())
+                .unwrap()
+        }
+    }
+}
+
+// User-configurable global hotkeys, persisted under the app data dir so
+// users can rebind them without rebuilding the app.
+#[derive(Clone, Serialize, Deserialize)]
+struct ShortcutBindings {
+    toggle_window: String,
+    toggle_backend: String,
+}
+
+impl Default for ShortcutBindings {
+    fn default() -> Self {
+        Self {
+            toggle_window: "CmdOrCtrl+Shift+G".to_string(),
+            toggle_backend: "CmdOrCtrl+Shift+S".to_string(),
+        }
+    }
+}
+
+// Reads shortcut bindings from `<app data dir>/shortcuts.json`, creating the
+// file with defaults on first run if it doesn't exist yet.
+fn load_shortcut_bindings(app_handle: &AppHandle) -> ShortcutBindings {
+    let Ok(app_data_dir) = app_handle.path().app_data_dir() else {
+        return ShortcutBindings::default();
+    };
+    let path = app_data_dir.join("shortcuts.json");
+
+    match std::fs::read_to_string(&path) {
+        Ok(contents) => serde_json::from_str(&contents).unwrap_or_default(),
+        Err(_) => {
+            let defaults = ShortcutBindings::default();
+            if let Err(e) = std::fs::create_dir_all(&app_data_dir) {
+                warn!("Failed to create app data directory for shortcuts: {}", e);
+                return defaults;
+            }
+            if let Ok(json) = serde_json::to_string_pretty(&defaults) {
+                if let Err(e) = std::fs::write(&path, json) {
+                    warn!("Failed to write default shortcuts.json: {}", e);
+                }
+            }
+            defaults
+        }
+    }
+}
+
+// Clears any previously registered hotkeys and registers `bindings` in their
+// place. Safe to call again at runtime after the user edits their bindings.
+fn register_shortcuts(app_handle: &AppHandle, bindings: &ShortcutBindings) -> Result<(), String> {
+    let global_shortcut = app_handle.global_shortcut();
+
+    // Parse both bindings before touching anything registered. Otherwise a bad
+    // hand-edited shortcuts.json (e.g. after unregister_all() but before the
+    // second parse fails) would leave the user with no shortcuts at all until
+    // they fix the file and call reload_shortcuts again.
+    let toggle_window: Shortcut = bindings.toggle_window.parse()
+        .map_err(|e| format!("Invalid toggle_window shortcut {:?}: {}", bindings.toggle_window, e))?;
+    let toggle_backend: Shortcut = bindings.toggle_backend.parse()
+        .map_err(|e| format!("Invalid toggle_backend shortcut {:?}: {}", bindings.toggle_backend, e))?;
+
+    global_shortcut.unregister_all()
+        .map_err(|e| format!("Failed to clear existing shortcuts: {}", e))?;
+
+    global_shortcut.on_shortcut(toggle_window, |app, _shortcut, event| {
+        if event.state() != ShortcutState::Pressed {
+            return;
+        }
+        if let Some(window) = app.get_webview_window("main") {
+            let is_visible = window.is_visible().unwrap_or(false);
+            if is_visible {
+                let _ = window.hide();
+            } else {
+                let _ = window.show();
+                let _ = window.set_focus();
+            }
+        }
+    }).map_err(|e| format!("Failed to register toggle_window shortcut: {}", e))?;
+
+    global_shortcut.on_shortcut(toggle_backend, |app, _shortcut, event| {
+        if event.state() != ShortcutState::Pressed {
+            return;
+        }
+        let app_handle = app.clone();
+        tauri::async_runtime::spawn(async move {
+            let backend: State<PythonBackend> = app_handle.state();
+            if backend.is_running() {
+                backend.stop(&app_handle);
+            } else if let Err(e) = backend.start(&app_handle).await {
+                error!("Failed to start Python backend via shortcut: {}", e);
+            }
+        });
+    }).map_err(|e| format!("Failed to register toggle_backend shortcut: {}", e))?;
+
+    Ok(())
+}
+
 // Tauri commands
 #[tauri::command]
-async fn get_backend_status(backend: State<'_, PythonBackend>) -> Result<bool, String> {
-    Ok(backend.is_running())
+async fn get_backend_status(backend: State<'_, PythonBackend>) -> Result<BackendStatus, String> {
+    Ok(backend.status())
 }
 
 #[tauri::command]
@@ -132,8 +551,8 @@ async fn restart_backend(
     app_handle: AppHandle,
 ) -> Result<(), String> {
     info!("Restarting Python backend");
-    backend.stop();
-    
+    backend.stop(&app_handle);
+
     sleep(Duration::from_secs(1)).await;
     
     backend.start(&app_handle).await
@@ -142,6 +561,21 @@ async fn restart_backend(
     Ok(())
 }
 
+#[tauri::command]
+async fn get_backend_url() -> Result<String, String> {
+    // The frontend always talks to the backend through the `gmaps://` proxy
+    // protocol below, never a raw `127.0.0.1:<port>` address, since the
+    // actual port is chosen at runtime and can change across restarts.
+    Ok("gmaps://localhost".to_string())
+}
+
+#[tauri::command]
+async fn reload_shortcuts(app_handle: AppHandle) -> Result<(), String> {
+    info!("Reloading global shortcuts");
+    let bindings = load_shortcut_bindings(&app_handle);
+    register_shortcuts(&app_handle, &bindings)
+}
+
 #[tauri::command]
 async fn get_app_version() -> Result<String, String> {
     Ok("2.0.0".to_string())
@@ -167,15 +601,29 @@ fn main() {
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_dialog::init())
+        .plugin(tauri_plugin_global_shortcut::Builder::new().build())
         .invoke_handler(tauri::generate_handler![
             get_backend_status,
             restart_backend,
+            get_backend_url,
             get_app_version,
-            open_external_url
+            open_external_url,
+            reload_shortcuts
         ])
+        .register_asynchronous_uri_scheme_protocol("gmaps", |app, request, responder| {
+            let app_handle = app.clone();
+            tauri::async_runtime::spawn(async move {
+                responder.respond(proxy_to_backend(&app_handle, request).await);
+            });
+        })
         .setup(|app| {
             let app_handle = app.handle().clone();
-            
+
+            let bindings = load_shortcut_bindings(&app_handle);
+            if let Err(e) = register_shortcuts(&app_handle, &bindings) {
+                error!("Failed to register global shortcuts: {}", e);
+            }
+
             // Start Python backend on app startup
             tauri::async_runtime::spawn(async move {
                 let backend: State<PythonBackend> = app_handle.state();
@@ -194,7 +642,7 @@ fn main() {
             if let tauri::WindowEvent::CloseRequested { .. } = event {
                 // Stop Python backend when app is closing
                 let backend: State<PythonBackend> = window.state();
-                backend.stop();
+                backend.stop(window.app_handle());
             }
         })
         .run(tauri::generate_context!())